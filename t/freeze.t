@@ -0,0 +1,57 @@
+#!/usr/bin/perl
+# freeze's output, read back as a listfile, should give the same remotes and local paths it was made from
+use strict;
+use warnings;
+use Cwd qw(abs_path);
+use File::Temp qw(tempdir);
+use FindBin;
+use Test::More;
+
+my $grm = abs_path("$FindBin::Bin/../grm");
+my $dir = tempdir(CLEANUP => 1);
+
+plan skip_all => 'git is not on PATH' if system('git --version >/dev/null 2>&1');
+
+sub sh {
+	system(@_) == 0 or die "'@_' failed\n";
+}
+
+# Run grm in the tree with config piped in rather than found in a conffile
+sub grm {
+	my ($config, @args) = @_;
+
+	open(my $handle, '>', "$dir/piped.conf") or die;
+	print $handle "* RECURSE_PREFIX * \n", map {"* $_ * $config->{$_}\n"} sort keys %$config;
+	close($handle);
+
+	my $output = qx{cd '$dir/tree' && '$grm' @args < '$dir/piped.conf'};
+	die "grm @args failed\n" if $?;
+	return $output;
+}
+
+my $base = "file://$dir/remotes";
+my %origins = (
+	'a'     => "$base/a.git",
+	'x/b'   => "$base/sub/b.git",
+	'y z/c' => "file://$dir/elsewhere/c.git",
+);
+for (sort keys %origins) {
+	sh('git', 'init', '-q', "$dir/tree/$_");
+	sh('git', '-C', "$dir/tree/$_", 'remote', 'add', 'origin', $origins{$_});
+}
+sh('git', 'init', '-q', "$dir/tree/orphan");
+
+my $config = {LIST_FN => '.grm.repos', REMOTE_BASE_URL => $base};
+my $listfile = grm($config, 'freeze');
+
+open(my $handle, '>', "$dir/tree/.grm.repos") or die;
+print $handle $listfile;
+close($handle);
+
+like($listfile, qr/^# no origin: orphan$/m, 'freeze flags repos without an origin');
+
+is(grm($config, 'list-lrel'), "a\nx/b\ny z/c\n", 'frozen local paths read back');
+is(grm($config, 'list-rurl'), "$base/a.git\n$base/sub/b.git\nfile://$dir/elsewhere/c.git\n",
+	'frozen remote URLs read back, including ones outside REMOTE_BASE_URL');
+
+done_testing;
@@ -0,0 +1,71 @@
+#!/usr/bin/perl
+# Unit tests for cat_path, listfile cells and retries; run with 'prove t/'
+use strict;
+use warnings;
+use FindBin;
+use Test::More;
+
+BEGIN {
+	do "$FindBin::Bin/../grm" or die "cannot load grm: ".($@ || $! || 'no true value')."\n";
+}
+
+our $config;
+
+# cat_path
+is(cat_path('a/./b'     ), 'a/b'  , 'cat_path drops . components');
+is(cat_path('a/b/../c'  ), 'a/c'  , 'cat_path resolves .. components');
+is(cat_path('../x'      ), '../x' , 'cat_path keeps a leading .. in relative paths');
+is(cat_path('/../x'     ), '/x'   , 'cat_path cannot go above the root');
+is(cat_path('a', 'b/../c'), 'a/c' , 'cat_path normalises across pieces');
+is(cat_path('a', '/b'   ), '/b'   , 'cat_path starts again at an absolute piece');
+is(cat_path(undef, '', 'x'), 'x'  , 'cat_path ignores undefined and empty pieces');
+is(cat_path(), undef              , 'cat_path of nothing is undef');
+
+is(cat_path(undef, 'file:///tmp/sb/r1.git'), 'file:///tmp/sb/r1.git', 'cat_path leaves file URLs alone');
+is(cat_path('dir', 'https://host/x/../y'  ), 'https://host/x/../y'  , 'cat_path leaves http(s) URLs alone');
+is(cat_path('https://host/base/', 'a/b'   ), 'https://host/base/a/b', 'cat_path appends to a URL without touching it');
+is(cat_path(undef, 'git@host:a//b.git'    ), 'git@host:a//b.git'    , 'cat_path leaves scp-style addresses alone');
+
+# parse_line
+is_deeply(parse_line("a * b * c\n", '*'), ['a', 'b', 'c', undef], 'repo lines split on the separator');
+is_deeply(parse_line("* K * v * w", '*'), ['', 'K', 'v * w', undef], 'config lines have three fields');
+is_deeply(parse_line("a | b * c"  , '|'), ['a', 'b * c', undef, undef], 'the separator is configurable');
+is(parse_line(" \t \n"            , '*'), undef, 'blank lines are skipped');
+
+# escape_fields inverts parse_line's splitting, for any cells
+is_deeply(parse_line(join(' * ', escape_fields('*', 'a * b', ' c\\', '#d', "\te ")), '*'), ['a * b', ' c\\', '#d', "\te "],
+	'escaped cells parse back as they were');
+
+srand(1);
+my @chars = ('a', '/', ' ', "\t", '*', '|', '\\', '#', '!', '.');
+for my $separator ('*', '|') {
+	my $failed;
+	for (1..2000) {
+		my @cells = map {join '', map {$chars[rand @chars]} 1..rand 8} 1..4;
+		$cells[0] = "a$cells[0]" unless length $cells[0]; # An empty first cell would make a config line
+
+		my $line = join(" $separator ", escape_fields($separator, @cells));
+		next if Test::More::eq_array(parse_line($line, $separator), \@cells);
+
+		$failed = $line;
+		last;
+	}
+	is($failed, undef, "random cells round-trip with separator '$separator'");
+}
+
+# is_transient
+ok( is_transient($_), "'$_' is transient") for (
+	'fatal: unable to access \'https://host/x/\': Could not resolve host: host',
+	'fatal: unable to access \'https://host/x/\': gnutls_handshake() failed: The TLS connection was non-properly terminated.',
+	'fatal: unable to access \'https://host/x/\': OpenSSL SSL_read: Connection was reset, errno 10054',
+	'fatal: unable to access \'https://host/x/\': OpenSSL SSL_connect: SSL_ERROR_SYSCALL in connection to host:443',
+	'fatal: the remote end hung up unexpectedly',
+);
+ok(!is_transient($_), "'$_' is fatal") for (
+	'fatal: unable to access \'https://host/x/\': SSL certificate problem: unable to get local issuer certificate',
+	'fatal: unable to access \'https://host/x/\': SSL: no alternative certificate subject name matches target host name',
+	'fatal: Authentication failed for \'https://host/x/\'',
+	'fatal: repository \'https://host/x/\' not found',
+);
+
+done_testing;
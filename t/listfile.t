@@ -0,0 +1,60 @@
+#!/usr/bin/perl
+# How listfile entries are read: disabled entries, duplicates, and the config each gets
+use strict;
+use warnings;
+use Cwd qw(abs_path);
+use File::Temp qw(tempdir);
+use FindBin;
+use Test::More;
+
+my $grm = abs_path("$FindBin::Bin/../grm");
+my $dir = tempdir(CLEANUP => 1);
+
+sub write_file {
+	my ($path, @lines) = @_;
+
+	open(my $handle, '>', "$dir/$path") or die "cannot write '$path'\n";
+	print $handle @lines;
+	close($handle);
+}
+
+# Run grm beside a listfile with config piped in rather than found in a conffile, returning its stdout and stderr
+sub grm {
+	write_file('piped.conf', "* RECURSE_PREFIX * \n* LIST_FN * .grm.repos\n");
+
+	my $output = qx{cd '$dir' && '$grm' @_ < piped.conf 2>stderr};
+	my $status = $?;
+
+	open(my $handle, '<', "$dir/stderr") or die;
+	my $errors = join '', <$handle>;
+	close($handle);
+
+	return ($output, $errors, $status);
+}
+
+# A disabled entry doesn't claim its local path, so a retired remote can stay listed beside its replacement
+write_file('.grm.repos', "!old/foo\nnew/foo\nnew/bar\n!old/bar\n");
+
+my ($output, $errors) = grm('list-rrel');
+is($output, "old/foo\nnew/foo\nnew/bar\nold/bar\n", 'disabled entries are listed beside active ones at the same path');
+is($errors, '', 'without any duplicate warning');
+
+($output, $errors, my $status) = grm('--strict-config', '--dry-run', '--quiet', 'clone');
+is($status, 0, '--strict-config accepts them');
+is($output, "would clone 'new/foo' into 'foo'\nwould check out 'foo'\nwould clone 'new/bar' into 'bar'\nwould check out 'bar'\n",
+	'only the active entries are processed');
+
+# Two active entries at one path are still caught
+write_file('.grm.repos', "old/foo\nnew/foo\n");
+
+($output, $errors) = grm('list-rrel');
+is($output, "old/foo\n", 'a second active entry at the same path is skipped');
+like($errors, qr/local path 'foo' is already listed at '\.grm\.repos':1; skipping/, 'with a warning');
+
+# Each entry is processed under the config at its line, though entries are collected before any is processed
+write_file('.grm.repos', "* REMOTE_BASE_URL * https://a\nx\ny\n* REMOTE_BASE_URL * https://b\nz\n* REMOTE_BASE_URL * \nw\n");
+
+($output) = grm('list-rurl');
+is($output, "https://a/x\nhttps://a/y\nhttps://b/z\nw\n", 'config lines apply to the entries after them');
+
+done_testing;
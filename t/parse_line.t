@@ -0,0 +1,53 @@
+#!/usr/bin/perl
+# Unit tests for how listfile lines are read; run with 'prove t/'
+use strict;
+use warnings;
+use FindBin;
+use Test::More;
+
+BEGIN {
+	do "$FindBin::Bin/../grm" or die "cannot load grm: ".($@ || $! || 'no true value')."\n";
+}
+
+our $config;
+
+# Data lines with tabs and no separator split on tabs, for files from tab-separated formats
+is_deeply(parse_line("a\tb\t\tc"  , '*'), ['a', 'b', 'c', undef], 'lines with tabs and no separator split on tabs');
+is_deeply(parse_line("a b\t* c"   , '*'), ["a b", 'c', undef, undef], 'lines with a separator ignore tabs');
+is_deeply(parse_line("*\tK\t* v"  , '*'), ['', 'K', 'v', undef], 'config lines never split on tabs');
+
+# A mixed file reads each line by its own separator
+{
+	my $text = "* LIST_FN * .grm.repos\nr1\tl1\nr2 * l2\nr3\tl3\t\tref\n";
+	my @cells;
+	open(my $handle, '<', \$text) or die;
+	while (<$handle>) {
+		my $result = parse_line($_, '*');
+		push @cells, [@$result[0..1]] if $result->[0];
+	}
+	is_deeply(\@cells, [['r1', 'l1'], ['r2', 'l2'], ['r3', 'l3']], 'tab and star lines mix in one file');
+}
+
+# Files edited on Windows: CRLF endings and a leading BOM
+is_deeply(parse_line("a * b\r\n", '*'), ['a', 'b', undef, undef], 'CRLF endings are accepted');
+
+# A listfile with a BOM and CRLF endings reads the same as one without
+{
+	my @read;
+	for my $text ("\xEF\xBB\xBF* KEY * value\r\n* OTHER * x\r\n\r\n", "* KEY * value\n* OTHER * x\n") {
+		local $config = {};
+		open(my $handle, '<', \$text) or die;
+		listfile_process($handle, undef);
+		push @read, {%$config};
+	}
+	is_deeply($read[0], {KEY => 'value', OTHER => 'x'}, 'a BOM and CRLF endings are stripped');
+	is_deeply($read[0], $read[1], 'a BOM and CRLF endings make no difference');
+}
+
+# Comments start with a '#' at the start of a line or after whitespace; elsewhere, or escaped, it's literal
+is(parse_line("  # note", '*'), undef, 'comment lines are skipped');
+is_deeply(parse_line('a * b # pinned for release', '*'), ['a', 'b', undef, undef], 'trailing comments are stripped');
+is_deeply(parse_line('https://h/x#frag * b'      , '*'), ['https://h/x#frag', 'b', undef, undef], 'a # within a token is literal');
+is_deeply(parse_line('a * b \# not a comment'    , '*'), ['a', 'b # not a comment', undef, undef], 'an escaped # is literal');
+
+done_testing;
@@ -0,0 +1,46 @@
+#!/usr/bin/perl
+# Unit tests for grm's path helpers; run with 'prove t/'
+use strict;
+use warnings;
+use FindBin;
+use Test::More;
+
+BEGIN {
+	do "$FindBin::Bin/../grm" or die "cannot load grm: ".($@ || $! || 'no true value')."\n";
+}
+
+our $config;
+
+# path_within
+ok( path_within('/home/me/proj'  , '/home/me/proj'), 'a path is within itself');
+ok( path_within('/home/me/proj/x', '/home/me/proj'), 'a path is within its ancestors');
+ok(!path_within('/home/me/proj'  , '/home/me/pro' ), 'a path is not within a prefix of a component');
+ok(!path_within('/home/me'       , '/home/me/proj'), 'a path is not within its descendants');
+ok( path_within('/x'             , '/'            ), 'everything is within the root');
+ok( path_within('C:/x'           , 'C:/'          ), 'everything on a drive is within its root');
+ok( path_within(normalise_path('/home/me/./proj/'), normalise_path('/home/me/proj/')), 'normalised . and trailing slashes compare equal');
+
+# Windows absolute paths, on any platform
+is(path_root('C:/x'         ), 'C:/' , 'a drive path is absolute');
+is(path_root('//server/x'   ), '//'  , 'a UNC path is absolute');
+is(path_root('x/C:/y'       ), undef , 'a drive later in a path is not a root');
+is(cat_path('a', 'C:/x/../y'), 'C:/y', 'cat_path starts again at a drive path');
+is(cat_path('a', '//srv/s/x'), '//srv/s/x', 'cat_path starts again at a UNC path');
+SKIP: {
+	skip 'backslashes are separators only on Windows', 2 unless $^O eq 'MSWin32';
+
+	is(path_root('C:\\x'), 'C:/', 'a backslashed drive path is absolute');
+	is(normalise_path('\\\\srv\\s\\..\\t'), '//srv/t', 'backslashed UNC paths normalise');
+}
+
+# path_join
+is(path_join('a', '', undef, 'b'), 'a/b'  , 'path_join skips empty segments');
+is(path_join('a/', '/b/', '/c/'  ), 'a/b/c', 'path_join puts exactly one slash between segments');
+is(path_join('a//b', 'c//'       ), 'a/b/c', 'path_join collapses doubled slashes within segments');
+is(path_join('/', 'x'            ), '/x'   , 'path_join keeps a root');
+is(path_join('/'                 ), '/'    , 'path_join keeps the trailing slash of a root alone');
+is(path_join('C:/', 'x'          ), 'C:/x' , 'path_join keeps a drive root');
+is(path_join('//server/share', 'x'), '//server/share/x', 'path_join keeps a UNC root');
+is(path_join(                    ), undef  , 'path_join of nothing is undef');
+
+done_testing;
@@ -0,0 +1,27 @@
+#!/usr/bin/perl
+# Unit tests for --porcelain field escaping; run with 'prove t/'
+use strict;
+use warnings;
+use FindBin;
+use Test::More;
+
+BEGIN {
+	do "$FindBin::Bin/../grm" or die "cannot load grm: ".($@ || $! || 'no true value')."\n";
+}
+
+our $config;
+
+# porcelain_escape, read back as the spec in its comment says
+sub porcelain_unescape {
+	my %unescapes = ('\\' => "\\", t => "\t", n => "\n", r => "\r");
+	return map {s/\\([\\tnr])/$unescapes{$1}/gr} @_;
+}
+{
+	my @fields = ("a\tb", "c\nd\r", 'e\\tf', '', "\\");
+	my $line = join("\t", porcelain_escape(@fields));
+	unlike($line, qr/[\n\r]/, 'porcelain fields are one line');
+	is_deeply([porcelain_unescape(split /\t/, $line, -1)], \@fields, 'porcelain fields round-trip');
+	is_deeply([porcelain_escape(undef)], [''], 'undef porcelain fields are empty');
+}
+
+done_testing;
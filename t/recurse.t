@@ -0,0 +1,47 @@
+#!/usr/bin/perl
+# Recursion looks for listfiles in sub-trees, but not inside repos unless asked to, and options beat listfiles throughout
+use strict;
+use warnings;
+use Cwd qw(abs_path);
+use File::Path qw(make_path);
+use File::Temp qw(tempdir);
+use FindBin;
+use Test::More;
+
+my $grm = abs_path("$FindBin::Bin/../grm");
+my $dir = tempdir(CLEANUP => 1);
+
+sub write_file {
+	my ($path, @lines) = @_;
+
+	open(my $handle, '>', "$dir/$path") or die "cannot write '$path'\n";
+	print $handle @lines;
+	close($handle);
+}
+
+# Run grm in the tree with config piped in rather than found in a conffile
+sub grm {
+	write_file('piped.conf', "* RECURSE_PREFIX * \n* OPT_RECURSE * 1\n* LIST_FN * .grm.repos\n");
+
+	my $output = qx{cd '$dir/tree' && '$grm' @_ < '$dir/piped.conf'};
+	die "grm @_ failed\n" if $?;
+	return $output;
+}
+
+make_path("$dir/tree/sub", "$dir/tree/clone/.git");
+write_file('tree/.grm.repos'      , "top\n* OPT_DRY_RUN * 0\n");
+write_file('tree/sub/.grm.repos'  , "* OPT_DRY_RUN * 0\nnested\n");
+write_file('tree/clone/.grm.repos', "committed\n");
+
+sub lines {
+	return join '', sort split /^/, $_[0];
+}
+
+is(lines(grm('list-lrel')), "sub/nested\ntop\n", 'listfiles inside repos are left alone');
+is(lines(grm('--recurse-into-repos', 'list-lrel')), "clone/committed\nsub/nested\ntop\n", '--recurse-into-repos reads them');
+
+my $clones = grm('--dry-run', '--quiet', 'clone');
+is(scalar(() = $clones =~ /^would clone /mg), 2, '--dry-run beats OPT_DRY_RUN in listfiles, including in sub-trees');
+ok(!-e "$dir/tree/top" && !-e "$dir/tree/sub/nested", 'nothing was cloned');
+
+done_testing;
@@ -0,0 +1,41 @@
+#!/usr/bin/perl
+# Unit tests for remote_url; run with 'prove t/'
+use strict;
+use warnings;
+use FindBin;
+use Test::More;
+
+BEGIN {
+	do "$FindBin::Bin/../grm" or die "cannot load grm: ".($@ || $! || 'no true value')."\n";
+}
+
+our $config;
+
+# http(s) paths are percent-encoded; other kinds of remote are left alone
+{
+	local $config = {REMOTE_BASE_URL => 'https://host/base'};
+	is(remote_url('a b/c#d?e.git'       ), 'https://host/base/a%20b/c%23d%3Fe.git', 'http(s) paths are percent-encoded');
+	is(remote_url('[x]/y.git'           ), 'https://host/base/%5Bx%5D/y.git'      , 'brackets are percent-encoded');
+	is(remote_url("caf\xc3\xa9.git"     ), 'https://host/base/caf%C3%A9.git'      , 'non-ASCII bytes are percent-encoded');
+
+	$config->{REMOTE_BASE_URL} = 'git@host:base/';
+	is(remote_url('/a b.git'  ), 'git@host:base/a b.git'  , 'scp-style remotes are joined as paths');
+
+	$config->{REMOTE_BASE_URL} = '/srv/git/';
+	is(remote_url('x.git'     ), '/srv/git/x.git'         , 'local remotes are joined as paths');
+
+	$config->{REMOTE_BASE_URL} = '';
+	is(remote_url('file:///tmp/x.git'), 'file:///tmp/x.git', 'without a base, remotes are taken as they are');
+}
+
+# git:// and ftp(s):// bases are joined with exactly one slash between segments, but not encoded
+{
+	local $config = {REMOTE_BASE_URL => 'git://host//base/'};
+	is(remote_url('/x//y.git/'), 'git://host/base/x/y.git', 'git:// URLs get exactly one slash between segments');
+	is(remote_url('a b.git'   ), 'git://host/base/a b.git', 'only http(s) paths are percent-encoded');
+
+	$config->{REMOTE_BASE_URL} = 'ftps://host';
+	is(remote_url('x.git'     ), 'ftps://host/x.git'      , 'a base with no path gets one');
+}
+
+done_testing;